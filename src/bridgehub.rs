@@ -1,9 +1,13 @@
 use std::collections::HashSet;
 use std::fmt::Display;
 
+use crate::governance::read_governance_status;
 use crate::sequencer::Sequencer;
+use crate::settlement_layer::{resolve_migrated_state_transition, GatewayRpcMap};
 use crate::statetransition::StateTransition;
 use crate::stm::detect_hyperchains;
+use crate::validator_timelock::ValidatorTimelock;
+use alloy::eips::BlockId;
 use alloy::primitives::{Address, FixedBytes, U256};
 use alloy::providers::{Provider, RootProvider};
 use alloy::rpc::types::Filter;
@@ -62,6 +66,10 @@ pub struct Bridgehub {
     pub shared_bridge: Address,
     pub known_chains: Option<HashSet<u64>>,
     provider: RootProvider<Http<Client>>,
+    block_id: BlockId,
+    gateway_rpcs: GatewayRpcMap,
+    known_validators: Vec<Address>,
+    known_operation_hashes: Vec<FixedBytes<32>>,
 }
 
 impl Display for Bridgehub {
@@ -92,8 +100,14 @@ impl Bridgehub {
             );
         }
 
+        let block_id = sequencer.block_id();
         let contract = IBridgehub::new(address, provider);
-        let shared_bridge = contract.sharedBridge().call().await?.sharedBridge;
+        let shared_bridge = contract
+            .sharedBridge()
+            .call()
+            .block(block_id)
+            .await?
+            .sharedBridge;
 
         let known_chains = if autodetect_chains {
             Some(Bridgehub::detect_chains(sequencer, address).await?)
@@ -106,8 +120,37 @@ impl Bridgehub {
             shared_bridge,
             known_chains,
             provider: sequencer.get_provider(),
+            block_id,
+            gateway_rpcs: GatewayRpcMap::default(),
+            known_validators: Vec::new(),
+            known_operation_hashes: Vec::new(),
         })
     }
+
+    /// Registers the chain-id -> RPC-url map used to follow chains onto their Gateway
+    /// settlement layer (see [`crate::settlement_layer`]).
+    pub fn with_gateway_rpcs(mut self, gateway_rpcs: GatewayRpcMap) -> Self {
+        self.gateway_rpcs = gateway_rpcs;
+        self
+    }
+
+    /// Registers the candidate operator addresses to probe each chain's
+    /// `ValidatorTimelock` for membership (see [`crate::validator_timelock`]).
+    pub fn with_known_validators(mut self, known_validators: Vec<Address>) -> Self {
+        self.known_validators = known_validators;
+        self
+    }
+
+    /// Registers the candidate operation hashes to probe each chain's
+    /// `ValidatorTimelock` for via `getTimelockOperation` (see [`crate::validator_timelock`]).
+    pub fn with_known_operation_hashes(
+        mut self,
+        known_operation_hashes: Vec<FixedBytes<32>>,
+    ) -> Self {
+        self.known_operation_hashes = known_operation_hashes;
+        self
+    }
+
     pub async fn detect_chains(
         sequencer: &Sequencer,
         bridgehub: Address,
@@ -129,7 +172,7 @@ impl Bridgehub {
         bridgehub: Address,
     ) -> eyre::Result<HashSet<u64>> {
         let provider = sequencer.get_provider();
-        let mut current_block = provider.get_block_number().await?;
+        let mut current_block = sequencer.resolve_block_number().await?;
         let mut known_chains = HashSet::new();
 
         while current_block > 0 {
@@ -170,6 +213,46 @@ impl Bridgehub {
             println!("{}", format!("  Chain: {:?}", chain_id).bold());
             let details = self.get_chain_details(chain_id).await?;
             println!("{}", details);
+
+            let validator_timelock = ValidatorTimelock::new(
+                &self.provider,
+                details.validator_timelock_address,
+                chain_id,
+                &self.known_validators,
+                &self.known_operation_hashes,
+                self.block_id,
+            )
+            .await?;
+            println!("{}", validator_timelock);
+
+            let state_transition = self.get_state_transition(chain_id).await?;
+            println!("{}", "  -- L1 record --".dimmed());
+            println!("{}", state_transition);
+
+            let governance = read_governance_status(
+                &self.provider,
+                state_transition.admin(),
+                details.stm_address,
+                state_transition.protocol_version(),
+                self.block_id,
+            )
+            .await?;
+            println!("{}", governance);
+
+            if let Some(migrated) = resolve_migrated_state_transition(
+                state_transition.settlement_layer(),
+                chain_id,
+                self.address,
+                &self.gateway_rpcs,
+            )
+            .await?
+            {
+                println!(
+                    "{}",
+                    "  -- Settlement layer record (authoritative) --".dimmed()
+                );
+                println!("{}", migrated);
+            }
         }
 
         Ok(())
@@ -188,10 +271,16 @@ impl Bridgehub {
         let stm_address = contract
             .stateTransitionManager(U256::from(chain_id))
             .call()
+            .block(self.block_id)
             .await?
             ._0;
 
-        let base_token_address = match contract.baseToken(U256::from(chain_id)).call().await {
+        let base_token_address = match contract
+            .baseToken(U256::from(chain_id))
+            .call()
+            .block(self.block_id)
+            .await
+        {
             Ok(base_token) => base_token._0,
             // FIXME: remove after we fix an issue where basetoken is not set after migration.
             Err(_) => Address::ZERO,
@@ -199,21 +288,29 @@ impl Bridgehub {
         let st_address = contract
             .getHyperchain(U256::from(chain_id))
             .call()
+            .block(self.block_id)
             .await?
             ._0;
-        let shared_bridge_address = contract.sharedBridge().call().await?.sharedBridge;
+        let shared_bridge_address = contract
+            .sharedBridge()
+            .call()
+            .block(self.block_id)
+            .await?
+            .sharedBridge;
 
         let stm_contract = StateTransitionManager::new(stm_address, &self.provider);
 
         let validator_timelock_address = stm_contract
             .validatorTimelock()
             .call()
+            .block(self.block_id)
             .await?
             .validatorTimelock;
 
         let asset_id = contract
             .stmAssetIdFromChainId(U256::from(chain_id))
             .call()
+            .block(self.block_id)
             .await?
             ._0;
 
@@ -233,8 +330,9 @@ impl Bridgehub {
         let st_address = contract
             .getHyperchain(U256::from(chain_id))
             .call()
+            .block(self.block_id)
             .await?
             ._0;
-        StateTransition::new(&self.provider, st_address).await
+        StateTransition::new(&self.provider, st_address, self.block_id).await
     }
 }
@@ -0,0 +1,179 @@
+use std::fmt::Display;
+
+use alloy::eips::BlockId;
+use alloy::primitives::{Address, FixedBytes, U256};
+use alloy::sol;
+use colored::Colorize;
+
+sol! {
+    #[sol(rpc)]
+    contract IChainAdmin {
+        address public pendingAdmin;
+    }
+
+    #[sol(rpc)]
+    contract IStateTransitionManager {
+        function protocolVersion() external view returns (uint256);
+        function protocolVersionDeadline(uint256 _protocolVersion) external view returns (uint256);
+        function l2SystemContractsUpgradeTxHash(uint256 _protocolVersion) external view returns (bytes32);
+    }
+}
+
+/// STM packs `(minor, patch)` into the low 8 bytes of the `uint256` it hands back from
+/// `protocolVersion()` (major is always 0) - mirrors `IHyperchain::getSemverProtocolVersion`
+/// on the chain side, just not split into three return values there.
+fn unpack_semver(version: U256) -> (u32, u32, u32) {
+    let bytes = version.to_be_bytes::<32>();
+    let minor = u32::from_be_bytes(bytes[24..28].try_into().unwrap());
+    let patch = u32::from_be_bytes(bytes[28..32].try_into().unwrap());
+    (0, minor, patch)
+}
+
+/// Pending governance actions for one chain: an admin transfer in flight, and whether the
+/// chain's protocol version lags what the StateTransitionManager has scheduled.
+pub struct GovernanceStatus {
+    pub chain_admin: Address,
+    pub pending_admin: Address,
+    pub chain_protocol_version: (u32, u32, u32),
+    pub stm_protocol_version: (u32, u32, u32),
+    pub upgrade_deadline: U256,
+    pub scheduled_upgrade_tx_hash: FixedBytes<32>,
+}
+
+impl GovernanceStatus {
+    pub fn is_behind_stm(&self) -> bool {
+        self.chain_protocol_version < self.stm_protocol_version
+    }
+
+    /// Whether the upgrade that would bring this chain up to the STM's target version is
+    /// still outstanding. `scheduled_upgrade_tx_hash` is keyed on the STM's *current*
+    /// target version, which stays non-zero forever once that version ships (it's the tx
+    /// that performed the upgrade) - so this only means "unexecuted" while the chain
+    /// hasn't caught up to that version yet; otherwise the chain already ran it.
+    pub fn has_unexecuted_scheduled_upgrade(&self) -> bool {
+        self.is_behind_stm() && self.scheduled_upgrade_tx_hash != FixedBytes::<32>::ZERO
+    }
+}
+
+impl Display for GovernanceStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "  Governance:")?;
+
+        if self.pending_admin == Address::ZERO {
+            writeln!(f, "    Pending admin:     none")?;
+        } else {
+            writeln!(
+                f,
+                "{}",
+                format!("    Pending admin:     {}", self.pending_admin).red()
+            )?;
+        }
+
+        let versions = format!(
+            "{}.{}.{} (chain) vs {}.{}.{} (STM)",
+            self.chain_protocol_version.0,
+            self.chain_protocol_version.1,
+            self.chain_protocol_version.2,
+            self.stm_protocol_version.0,
+            self.stm_protocol_version.1,
+            self.stm_protocol_version.2
+        );
+        if self.is_behind_stm() {
+            writeln!(
+                f,
+                "{}",
+                format!("    Protocol version:  {}", versions).red()
+            )?;
+        } else {
+            writeln!(f, "    Protocol version:  {}", versions)?;
+        }
+
+        if self.has_unexecuted_scheduled_upgrade() {
+            writeln!(
+                f,
+                "{}",
+                format!(
+                    "    Scheduled upgrade: tx {} due by block/timestamp {}",
+                    self.scheduled_upgrade_tx_hash, self.upgrade_deadline
+                )
+                .red()
+            )?;
+        } else {
+            writeln!(f, "    Scheduled upgrade: none")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the pending admin transfer on `chain_admin` and compares the chain's current
+/// protocol version against what `stm_address` has scheduled.
+pub async fn read_governance_status(
+    provider: &alloy::providers::RootProvider<
+        alloy::transports::http::Http<alloy::transports::http::Client>,
+    >,
+    chain_admin: Address,
+    stm_address: Address,
+    chain_protocol_version: (u32, u32, u32),
+    block_id: BlockId,
+) -> eyre::Result<GovernanceStatus> {
+    let chain_admin_contract = IChainAdmin::new(chain_admin, provider);
+    let pending_admin = match chain_admin_contract
+        .pendingAdmin()
+        .call()
+        .block(block_id)
+        .await
+    {
+        Ok(result) => result.pendingAdmin,
+        // Not every ChainAdmin deployment exposes a public `pendingAdmin` - treat "can't
+        // read it" the same as "nothing pending" rather than aborting the whole report.
+        Err(_) => Address::ZERO,
+    };
+
+    let stm_contract = IStateTransitionManager::new(stm_address, provider);
+    // The STM's target version - the one every chain is expected to converge to. Whether
+    // the upgrade that gets a given chain there is still outstanding depends on whether
+    // that chain has reached it yet (see `GovernanceStatus::has_unexecuted_scheduled_upgrade`).
+    let stm_protocol_version = unpack_semver(
+        stm_contract
+            .protocolVersion()
+            .call()
+            .block(block_id)
+            .await?
+            ._0,
+    );
+
+    let stm_version_packed =
+        U256::from(stm_protocol_version.1) << 32 | U256::from(stm_protocol_version.2);
+    // `protocolVersionDeadline`/`l2SystemContractsUpgradeTxHash` are STM-version-specific
+    // surfaces that vary across deployments - fall back like `baseToken` in
+    // `Bridgehub::get_chain_details` rather than
+    // aborting the report for every chain over one STM that doesn't expose them.
+    let upgrade_deadline = match stm_contract
+        .protocolVersionDeadline(stm_version_packed)
+        .call()
+        .block(block_id)
+        .await
+    {
+        Ok(result) => result._0,
+        Err(_) => U256::ZERO,
+    };
+    let scheduled_upgrade_tx_hash = match stm_contract
+        .l2SystemContractsUpgradeTxHash(stm_version_packed)
+        .call()
+        .block(block_id)
+        .await
+    {
+        Ok(result) => result._0,
+        Err(_) => FixedBytes::<32>::ZERO,
+    };
+
+    Ok(GovernanceStatus {
+        chain_admin,
+        pending_admin,
+        chain_protocol_version,
+        stm_protocol_version,
+        upgrade_deadline,
+        scheduled_upgrade_tx_hash,
+    })
+}
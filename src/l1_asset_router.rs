@@ -1,7 +1,7 @@
 use std::{collections::HashMap, fmt::Display};
 
 use alloy::{
-    primitives::{address, Address, FixedBytes, U256},
+    primitives::{address, Address, FixedBytes, I256, U256},
     sol,
     sol_types::SolEvent,
 };
@@ -28,6 +28,21 @@ sol! {
             bytes32 indexed additionalData,
             address sender
         );
+
+        // Bridge-in: funds deposited on L1 for `chainId`, bound for the given asset.
+        event BridgehubDepositFinalized(
+            uint256 indexed chainId,
+            bytes32 indexed txDataHash,
+            bytes32 indexed assetId,
+            bytes assetData
+        );
+
+        // Bridge-out: an L2 withdrawal for `chainId` finalized (claimed) on L1.
+        event WithdrawalFinalizedAssetRouter(
+            uint256 indexed chainId,
+            bytes32 indexed assetId,
+            bytes assetData
+        );
     }
 
     #[sol(rpc)]
@@ -79,6 +94,7 @@ impl RegisteredAsset {
                 let token_address = native_token_vault_contract
                     .tokenAddress(asset_id)
                     .call()
+                    .block(sequencer.block_id())
                     .await
                     .unwrap()
                     ._0;
@@ -88,7 +104,13 @@ impl RegisteredAsset {
                         "ETH".to_owned()
                     } else {
                         let erc20_contract = ERC20::new(token_address, sequencer.get_provider());
-                        erc20_contract.name().call().await.unwrap()._0
+                        erc20_contract
+                            .name()
+                            .call()
+                            .block(sequencer.block_id())
+                            .await
+                            .unwrap()
+                            ._0
                     };
 
                 AssetHandler::NativeTokenVault(NativeTokenVaultAsset {
@@ -153,9 +175,26 @@ impl L1AssetRouter {
         let provider = sequencer.get_provider();
         let contract = IL1AssetRouter::new(address, provider);
 
-        let native_token_vault = contract.nativeTokenVault().call().await.unwrap()._0;
-        let bridgehub = contract.BRIDGE_HUB().call().await.unwrap()._0;
+        let native_token_vault = contract
+            .nativeTokenVault()
+            .call()
+            .block(sequencer.block_id())
+            .await
+            .unwrap()
+            ._0;
+        let bridgehub = contract
+            .BRIDGE_HUB()
+            .call()
+            .block(sequencer.block_id())
+            .await
+            .unwrap()
+            ._0;
 
+        // NOTE: `get_all_events` scans the unbounded log history and is not pinned to
+        // `sequencer.block_id()`, unlike the `nativeTokenVault`/`BRIDGE_HUB` reads above -
+        // when the sequencer is pinned to a block this can surface assets registered after
+        // it. Bounding the scan (e.g. via `Sequencer::resolve_block_number`) belongs in
+        // whatever scans the logs, not here.
         let registered_assets = get_all_events(
             sequencer,
             address,
@@ -199,16 +238,166 @@ impl L1AssetRouter {
         let address = contract
             .tokenAddress(asset_id.clone())
             .call()
+            .block(sequencer.block_id())
             .await
             .unwrap()
             ._0;
         let balance = contract
             .chainBalance(chain_id, address)
             .call()
+            .block(sequencer.block_id())
             .await
             .unwrap()
             ._0;
 
         balance
     }
+
+    /// Reconstructs `chainBalance(chain_id, asset_id)` from the bridge-in/bridge-out
+    /// history and compares it against the value the vault actually reports. The result is
+    /// approximate, not a verified audit, for two reasons: `decode_leading_amount` hasn't
+    /// been confirmed against the real `NativeTokenVault` transfer-data layout, and
+    /// `get_all_events` below scans the unbounded log history rather than being pinned to
+    /// `sequencer.block_id()` like `chain_balance` is - on a block-pinned sequencer the two sides can
+    /// therefore be read at different heights. See [`BalanceReconciliation`].
+    pub async fn reconcile_chain_balance(
+        &self,
+        sequencer: &Sequencer,
+        chain_id: U256,
+        asset_id: &FixedBytes<32>,
+    ) -> eyre::Result<BalanceReconciliation> {
+        let deposits = get_all_events(
+            sequencer,
+            self.address,
+            IL1AssetRouter::BridgehubDepositFinalized::SIGNATURE_HASH,
+        )
+        .await?
+        .into_iter()
+        .filter(|log| log.topics().get(1).map(|t| U256::from(*t)) == Some(chain_id))
+        .filter(|log| log.topics().get(3).map(|t| t.0) == Some(asset_id.0))
+        .map(|log| {
+            let tx_hash = log.transaction_hash.unwrap_or_default();
+            let amount = decode_leading_amount(log.data().data.as_ref());
+            (tx_hash, amount)
+        })
+        .collect::<Vec<_>>();
+
+        let withdrawals = get_all_events(
+            sequencer,
+            self.address,
+            IL1AssetRouter::WithdrawalFinalizedAssetRouter::SIGNATURE_HASH,
+        )
+        .await?
+        .into_iter()
+        .filter(|log| log.topics().get(1).map(|t| U256::from(*t)) == Some(chain_id))
+        .filter(|log| log.topics().get(2).map(|t| t.0) == Some(asset_id.0))
+        .map(|log| {
+            let tx_hash = log.transaction_hash.unwrap_or_default();
+            let amount = decode_leading_amount(log.data().data.as_ref());
+            (tx_hash, amount)
+        })
+        .collect::<Vec<_>>();
+
+        let deposits_total = deposits
+            .iter()
+            .map(|(_, amount)| *amount)
+            .fold(U256::ZERO, U256::saturating_add);
+        let withdrawals_total = withdrawals
+            .iter()
+            .map(|(_, amount)| *amount)
+            .fold(U256::ZERO, U256::saturating_add);
+
+        // Withdrawals can legitimately exceed deposits within the scanned window (a
+        // partial log range, or net outflow), so this is a signed quantity - a plain
+        // U256 subtraction would panic on underflow.
+        let computed_balance = I256::try_from(deposits_total)
+            .unwrap_or(I256::MAX)
+            .saturating_sub(I256::try_from(withdrawals_total).unwrap_or(I256::MAX));
+
+        let on_chain_balance = self.chain_balance(sequencer, chain_id, asset_id).await;
+
+        Ok(BalanceReconciliation {
+            asset_id: *asset_id,
+            chain_id,
+            computed_balance,
+            on_chain_balance,
+            deposits,
+            withdrawals,
+        })
+    }
+
+    /// Prints the router's registered assets together with a reconciliation of each
+    /// one's `chainBalance` against its scanned bridge-in/bridge-out history.
+    pub async fn print_detailed_info(
+        &self,
+        sequencer: &Sequencer,
+        chain_id: U256,
+    ) -> eyre::Result<()> {
+        println!("{}", self);
+
+        for asset in self.registered_assets.values() {
+            let reconciliation = self
+                .reconcile_chain_balance(sequencer, chain_id, &asset.asset_id)
+                .await?;
+            println!("{}", reconciliation);
+        }
+
+        Ok(())
+    }
+}
+
+// Non-indexed params encode as a dynamic ABI tail: `[offset=0x20][length][content...]`, so
+// the actual `assetData` content starts at byte 64. We read the leading `uint256` word of
+// that content as the transferred amount, but this has NOT been verified against the real
+// `NativeTokenVault` transfer-data encoding (which may lead with other fields first) - treat
+// the result as approximate. See `BalanceReconciliation`, which labels it as such.
+fn decode_leading_amount(asset_data: &[u8]) -> U256 {
+    if asset_data.len() < 96 {
+        return U256::ZERO;
+    }
+    U256::from_be_slice(&asset_data[64..96])
+}
+
+pub struct BalanceReconciliation {
+    pub asset_id: FixedBytes<32>,
+    pub chain_id: U256,
+    pub computed_balance: I256,
+    pub on_chain_balance: U256,
+    pub deposits: Vec<(FixedBytes<32>, U256)>,
+    pub withdrawals: Vec<(FixedBytes<32>, U256)>,
+}
+
+impl Display for BalanceReconciliation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Reconciliation for chain {} / asset {}:",
+            self.chain_id, self.asset_id
+        )?;
+        writeln!(f, "  On-chain chainBalance: {}", self.on_chain_balance)?;
+
+        // Approximate, unverified: the decoded amount field and the unbounded log scan
+        // (see `reconcile_chain_balance`) aren't confirmed correct, so a difference here
+        // isn't a reliable signal of a real accounting bug - no red alarm on it.
+        let on_chain_as_signed = I256::try_from(self.on_chain_balance).unwrap_or(I256::MAX);
+        let note = if self.computed_balance == on_chain_as_signed {
+            ""
+        } else {
+            " (approximate, unverified - differs from on-chain value)"
+        };
+        writeln!(
+            f,
+            "  Reconstructed balance: {}{}",
+            self.computed_balance, note
+        )?;
+
+        for (tx_hash, amount) in &self.deposits {
+            writeln!(f, "    + {} deposit in tx {}", amount, tx_hash)?;
+        }
+        for (tx_hash, amount) in &self.withdrawals {
+            writeln!(f, "    - {} withdrawal in tx {}", amount, tx_hash)?;
+        }
+
+        Ok(())
+    }
 }
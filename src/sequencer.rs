@@ -0,0 +1,72 @@
+use alloy::eips::{BlockId, BlockNumberOrTag};
+use alloy::providers::{Provider, ProviderBuilder, RootProvider};
+use alloy::transports::http::{Client, Http};
+use eyre::OptionExt;
+
+/// Distinguishes whether a [`Sequencer`] talks to L1 or to a given L2/Gateway chain.
+#[derive(Debug, Clone, Copy)]
+pub enum SequencerType {
+    L1,
+    L2(u64),
+}
+
+/// Wraps an RPC connection to a chain, optionally pinned to a historical block so that
+/// every read made through it (and through the objects constructed from it) sees a single
+/// consistent view of state, as of that block.
+pub struct Sequencer {
+    pub sequencer_type: SequencerType,
+    provider: RootProvider<Http<Client>>,
+    at_block: Option<BlockId>,
+}
+
+impl Sequencer {
+    pub async fn new(rpc_url: &str, sequencer_type: SequencerType) -> eyre::Result<Self> {
+        Self::new_at_block(rpc_url, sequencer_type, None).await
+    }
+
+    /// Like [`Sequencer::new`], but pins every subsequent read to `at_block` (a block
+    /// number or hash), instead of `latest`.
+    pub async fn new_at_block(
+        rpc_url: &str,
+        sequencer_type: SequencerType,
+        at_block: Option<BlockId>,
+    ) -> eyre::Result<Self> {
+        let provider = ProviderBuilder::new().on_http(rpc_url.parse()?);
+        Ok(Self {
+            sequencer_type,
+            provider,
+            at_block,
+        })
+    }
+
+    pub fn get_provider(&self) -> RootProvider<Http<Client>> {
+        self.provider.clone()
+    }
+
+    /// The block id that every `.call()` made through this sequencer should be pinned to.
+    /// Falls back to `latest` when no `at_block` was given.
+    pub fn block_id(&self) -> BlockId {
+        self.at_block.unwrap_or_else(BlockId::latest)
+    }
+
+    pub fn at_block(&self) -> Option<BlockId> {
+        self.at_block
+    }
+
+    /// Resolves [`Sequencer::at_block`] down to a concrete block number - needed for APIs
+    /// like log filters that only accept numeric bounds, not hashes or tags.
+    pub async fn resolve_block_number(&self) -> eyre::Result<u64> {
+        match self.at_block {
+            None => Ok(self.provider.get_block_number().await?),
+            Some(BlockId::Number(BlockNumberOrTag::Number(n))) => Ok(n),
+            Some(block_id) => {
+                let block = self
+                    .provider
+                    .get_block(block_id)
+                    .await?
+                    .ok_or_eyre("at_block does not refer to a known block")?;
+                Ok(block.header.number)
+            }
+        }
+    }
+}
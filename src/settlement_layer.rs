@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use alloy::primitives::{Address, U256};
+use colored::Colorize;
+
+use crate::bridgehub::Bridgehub;
+use crate::sequencer::{Sequencer, SequencerType};
+use crate::statetransition::StateTransition;
+
+/// Maps a settlement-layer chain id to the RPC endpoint the debugger should use to talk
+/// to it, so migrated chains can be re-resolved on their Gateway instead of on L1.
+#[derive(Default, Clone)]
+pub struct GatewayRpcMap(HashMap<u64, String>);
+
+impl GatewayRpcMap {
+    pub fn new(rpc_urls: HashMap<u64, String>) -> Self {
+        Self(rpc_urls)
+    }
+
+    pub fn rpc_url_for(&self, chain_id: u64) -> Option<&str> {
+        self.0.get(&chain_id).map(String::as_str)
+    }
+}
+
+/// The authoritative, settlement-layer-side view of a chain that has migrated off L1.
+pub struct MigratedStateTransition {
+    pub settlement_layer_chain_id: u64,
+    pub state_transition: StateTransition,
+}
+
+impl Display for MigratedStateTransition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "  Settlement layer chain id: {}",
+            self.settlement_layer_chain_id
+        )?;
+        write!(f, "{}", self.state_transition)
+    }
+}
+
+/// Given the `settlement_layer` address read off a chain's `IHyperchain`, re-resolves that
+/// chain's state on the settlement layer (Gateway) it migrated to, instead of on L1.
+///
+/// `settlement_layer` encodes the Gateway's chain id (zero means "not migrated, L1 is
+/// authoritative"). Returns `Ok(None)` when the chain hasn't migrated, when
+/// `settlement_layer` doesn't fit a chain id, or when the caller hasn't configured an RPC
+/// for that Gateway chain id.
+pub async fn resolve_migrated_state_transition(
+    settlement_layer: Address,
+    migrated_chain_id: u64,
+    bridgehub_address: Address,
+    gateway_rpcs: &GatewayRpcMap,
+) -> eyre::Result<Option<MigratedStateTransition>> {
+    if settlement_layer == Address::ZERO {
+        return Ok(None);
+    }
+
+    // `getSettlementLayer()` is declared to return a genuine `address`, but in practice it
+    // holds the Gateway's chain id encoded as one. If it ever holds something that doesn't
+    // fit a chain id (a real address, or a value too large), we have no RPC to follow it to
+    // anyway, so fail gracefully rather than panicking on the numeric conversion.
+    let Ok(settlement_layer_chain_id) =
+        u64::try_from(U256::from_be_slice(settlement_layer.as_slice()))
+    else {
+        return Ok(None);
+    };
+
+    let Some(rpc_url) = gateway_rpcs.rpc_url_for(settlement_layer_chain_id) else {
+        return Ok(None);
+    };
+
+    let gateway_sequencer =
+        Sequencer::new(rpc_url, SequencerType::L2(settlement_layer_chain_id)).await?;
+
+    // The Bridgehub is deployed at the same address on L1 and on every Gateway chain.
+    let gateway_bridgehub = Bridgehub::new(&gateway_sequencer, bridgehub_address, false).await?;
+    let state_transition = gateway_bridgehub
+        .get_state_transition(migrated_chain_id)
+        .await?;
+
+    Ok(Some(MigratedStateTransition {
+        settlement_layer_chain_id,
+        state_transition,
+    }))
+}
@@ -1,5 +1,6 @@
 use std::fmt::Display;
 
+use alloy::eips::BlockId;
 use alloy::primitives::FixedBytes;
 use alloy::primitives::{Address, U256};
 use alloy::sol;
@@ -91,32 +92,82 @@ impl Display for StateTransition {
 }
 
 impl StateTransition {
+    /// The settlement layer this chain has migrated to, or `Address::ZERO` if it is still
+    /// settling directly on L1.
+    pub fn settlement_layer(&self) -> Address {
+        self.settlement_layer
+    }
+
+    pub fn admin(&self) -> Address {
+        self.admin
+    }
+
+    pub fn protocol_version(&self) -> (u32, u32, u32) {
+        self.protocol_version
+    }
+
     pub async fn new(
         provider: &alloy::providers::RootProvider<
             alloy::transports::http::Http<alloy::transports::http::Client>,
         >,
         hyperchain: Address,
+        block_id: BlockId,
     ) -> eyre::Result<StateTransition> {
         let contract = IHyperchain::new(hyperchain, provider);
 
-        let verifier = contract.getVerifier().call().await?._0;
-        let total_batches_committed = contract.getTotalBatchesCommitted().call().await?._0;
-        let total_batches_verified = contract.getTotalBatchesCommitted().call().await?._0;
-        let total_batches_executed = contract.getTotalBatchesCommitted().call().await?._0;
-        let protocol_version = contract.getSemverProtocolVersion().call().await?;
+        let verifier = contract.getVerifier().call().block(block_id).await?._0;
+        let total_batches_committed = contract
+            .getTotalBatchesCommitted()
+            .call()
+            .block(block_id)
+            .await?
+            ._0;
+        let total_batches_verified = contract
+            .getTotalBatchesVerified()
+            .call()
+            .block(block_id)
+            .await?
+            ._0;
+        let total_batches_executed = contract
+            .getTotalBatchesExecuted()
+            .call()
+            .block(block_id)
+            .await?
+            ._0;
+        let protocol_version = contract
+            .getSemverProtocolVersion()
+            .call()
+            .block(block_id)
+            .await?;
 
-        let admin = contract.getAdmin().call().await?._0;
+        let admin = contract.getAdmin().call().block(block_id).await?._0;
 
-        let bootloader_hash = contract.getL2BootloaderBytecodeHash().call().await?._0;
-        let default_account_hash = contract.getL2DefaultAccountBytecodeHash().call().await?._0;
+        let bootloader_hash = contract
+            .getL2BootloaderBytecodeHash()
+            .call()
+            .block(block_id)
+            .await?
+            ._0;
+        let default_account_hash = contract
+            .getL2DefaultAccountBytecodeHash()
+            .call()
+            .block(block_id)
+            .await?
+            ._0;
         let system_upgrade_tx_hash = contract
             .getL2SystemContractsUpgradeTxHash()
             .call()
+            .block(block_id)
             .await?
             ._0;
 
-        let chain_id = contract.getChainId().call().await?._0;
-        let settlement_layer = contract.getSettlementLayer().call().await?._0;
+        let chain_id = contract.getChainId().call().block(block_id).await?._0;
+        // FIXME: same spirit as the baseToken fallback in `Bridgehub::get_chain_details` - getSettlementLayer didn't
+        // exist on older deployments, so treat "can't read it" as "not migrated".
+        let settlement_layer = match contract.getSettlementLayer().call().block(block_id).await {
+            Ok(result) => result._0,
+            Err(_) => Address::ZERO,
+        };
 
         Ok(StateTransition {
             verifier,
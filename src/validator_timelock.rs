@@ -0,0 +1,175 @@
+use std::fmt::Display;
+
+use alloy::eips::BlockId;
+use alloy::primitives::{Address, FixedBytes, U256};
+use alloy::providers::Provider;
+use alloy::sol;
+use colored::Colorize;
+
+sol! {
+    #[sol(rpc)]
+    contract IValidatorTimelock {
+        function validators(uint256 _chainId, address _validator) external view returns (bool);
+        function executionDelay() external view returns (uint32);
+
+        struct TimelockOperation {
+            uint64 chainId;
+            bool completed;
+            uint40 timestamp;
+        }
+
+        function getTimelockOperation(uint256 _chainId, bytes32 _operationHash) external view returns (TimelockOperation memory);
+    }
+}
+
+/// A priority operation (commit/prove/execute) queued inside the timelock, still waiting
+/// out `execution_delay` before it can be applied.
+#[derive(Debug)]
+pub struct PendingOperation {
+    pub operation_hash: FixedBytes<32>,
+    pub queued_at: U256,
+}
+
+/// Read-only view of a chain's `ValidatorTimelock`: who may currently commit/prove/execute
+/// its batches, and what the delay is before a queued operation can land.
+///
+/// `pending_operations` only reflects `known_operation_hashes` that were actually checked
+/// (see [`ValidatorTimelock::new`]) - an empty list means "none of the checked candidates
+/// are pending", not "this chain has nothing queued".
+#[derive(Debug)]
+pub struct ValidatorTimelock {
+    pub address: Address,
+    pub chain_id: u64,
+    pub execution_delay: Option<u32>,
+    pub validators: Vec<Address>,
+    pub checked_operations: usize,
+    pub pending_operations: Vec<PendingOperation>,
+}
+
+impl Display for ValidatorTimelock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "  Validator timelock: {}", self.address)?;
+        match self.execution_delay {
+            Some(delay) => writeln!(f, "    Execution delay:   {} seconds", delay)?,
+            None => writeln!(f, "    Execution delay:   couldn't read")?,
+        };
+        writeln!(f, "    Validators:")?;
+        for validator in &self.validators {
+            writeln!(f, "      {}", validator)?;
+        }
+
+        if self.pending_operations.is_empty() {
+            writeln!(
+                f,
+                "    Pending operations: none of {} candidate(s) checked",
+                self.checked_operations
+            )?;
+        } else {
+            writeln!(
+                f,
+                "{}",
+                format!(
+                    "    Pending operations: {} of {} candidate(s) checked still inside the delay window",
+                    self.pending_operations.len(),
+                    self.checked_operations
+                )
+                .red()
+            )?;
+            for op in &self.pending_operations {
+                writeln!(f, "      {} queued at {}", op.operation_hash, op.queued_at)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ValidatorTimelock {
+    /// `known_validators` is the set of addresses to probe `validators(chain_id, _)` for,
+    /// and `known_operation_hashes` the set of operation hashes to probe via
+    /// `getTimelockOperation` - the contract exposes membership/lookup checks only, not
+    /// enumeration, so callers pass the candidates worth asking about (e.g. the chain's
+    /// configured operator set, and operation hashes derived from recent commit calldata).
+    pub async fn new(
+        provider: &alloy::providers::RootProvider<
+            alloy::transports::http::Http<alloy::transports::http::Client>,
+        >,
+        address: Address,
+        chain_id: u64,
+        known_validators: &[Address],
+        known_operation_hashes: &[FixedBytes<32>],
+        block_id: BlockId,
+    ) -> eyre::Result<ValidatorTimelock> {
+        let contract = IValidatorTimelock::new(address, provider);
+
+        // Not every timelock deployment matches this `sol!` declaration exactly - degrade
+        // to "couldn't read" rather than aborting the whole chain's report over it.
+        let execution_delay = match contract.executionDelay().call().block(block_id).await {
+            Ok(result) => Some(result._0),
+            Err(_) => None,
+        };
+
+        let mut validators = Vec::new();
+        for candidate in known_validators {
+            let is_validator = contract
+                .validators(U256::from(chain_id), *candidate)
+                .call()
+                .block(block_id)
+                .await?
+                ._0;
+            if is_validator {
+                validators.push(*candidate);
+            }
+        }
+
+        // Timestamp of the block the reads are pinned to, used to tell operations still
+        // inside the delay window apart from ones whose delay has already run out.
+        let now = provider
+            .get_block(block_id)
+            .await?
+            .map(|block| block.header.timestamp);
+
+        let mut pending_operations = Vec::new();
+        let mut checked_operations = 0;
+        for operation_hash in known_operation_hashes {
+            // Same spirit as `execution_delay` above: a revert here means this candidate
+            // couldn't be checked, not that the whole chain's report should be abandoned.
+            let Ok(operation) = contract
+                .getTimelockOperation(U256::from(chain_id), *operation_hash)
+                .call()
+                .block(block_id)
+                .await
+                .map(|result| result._0)
+            else {
+                continue;
+            };
+            checked_operations += 1;
+
+            // When the delay or the block time can't be read, a not-completed operation is
+            // kept as pending.
+            let delay_elapsed = match (now, execution_delay) {
+                (Some(now), Some(delay)) => {
+                    u64::from(operation.timestamp) + u64::from(delay) <= now
+                }
+                _ => false,
+            };
+
+            // `timestamp == 0` means this hash was never scheduled at all.
+            if operation.timestamp != 0 && !operation.completed && !delay_elapsed {
+                pending_operations.push(PendingOperation {
+                    operation_hash: *operation_hash,
+                    queued_at: U256::from(operation.timestamp),
+                });
+            }
+        }
+
+        Ok(ValidatorTimelock {
+            address,
+            chain_id,
+            execution_delay,
+            validators,
+            checked_operations,
+            pending_operations,
+        })
+    }
+}